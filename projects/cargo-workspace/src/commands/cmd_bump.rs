@@ -0,0 +1,102 @@
+use crate::{
+    CommandOptions,
+    errors::Result,
+    helpers::{
+        conventional::{apply_bump, bump_level_for_package, commits_since, last_release_tag, workspace_version_bump_level, BumpLevel},
+        workspace::write_package_version,
+    },
+};
+use clap::Parser;
+use std::path::PathBuf;
+
+/// Derives a new version for every changed package from its conventional
+/// commit history and writes it back into the relevant `Cargo.toml`.
+#[derive(Debug, Parser)]
+pub struct BumpCommand {
+    /// The path to the workspace root directory
+    #[arg(short, long, default_value = ".")]
+    pub workspace_root: PathBuf,
+
+    /// Compute and print the bump plan without writing any manifest
+    #[arg(long)]
+    pub dry_run: bool,
+}
+
+impl BumpCommand {
+    pub async fn run(&self, shared: &CommandOptions) -> Result<()> {
+        let workspace_root =
+            if self.workspace_root != PathBuf::from(".") { self.workspace_root.clone() } else { shared.workspace_root.clone() };
+        let dry_run = self.dry_run || shared.dry_run;
+
+        let workspace = crate::helpers::workspace::discover_workspace_packages(&workspace_root)?;
+        let since_tag = last_release_tag(&workspace_root)?;
+        let commits = commits_since(&workspace_root, since_tag.as_deref())?;
+
+        let mut plan: Vec<(String, String, String)> = Vec::new();
+
+        let mut ids: Vec<&crate::helpers::workspace::PackageId> = workspace.packages.keys().collect();
+        ids.sort();
+
+        // `[workspace.package] version` is a single shared value, so it must
+        // be bumped by the highest level among *all* inheriting packages'
+        // commits, not just whichever package is processed first.
+        let workspace_level = workspace_version_bump_level(workspace.packages.values(), &commits, &workspace_root);
+
+        for id in &ids {
+            let package = &workspace.packages[*id];
+            if package.inherits_workspace_version {
+                continue;
+            }
+
+            let level = bump_level_for_package(&commits, &workspace_root, &package.path);
+            if level == BumpLevel::None {
+                continue;
+            }
+
+            let new_version = apply_bump(&package.version, level)?;
+            plan.push((package.name.clone(), package.version.clone(), new_version.clone()));
+
+            if !dry_run {
+                write_package_version(&package.path.join("Cargo.toml"), &new_version, false)?;
+            }
+        }
+
+        if workspace_level != BumpLevel::None {
+            let current_version =
+                workspace.packages.values().find(|package| package.inherits_workspace_version).map(|package| package.version.as_str());
+            let current_version = current_version.expect("a bump level implies at least one inheriting package");
+            let new_version = apply_bump(current_version, workspace_level)?;
+
+            for id in &ids {
+                let package = &workspace.packages[*id];
+                if package.inherits_workspace_version {
+                    plan.push((package.name.clone(), package.version.clone(), new_version.clone()));
+                }
+            }
+
+            if !dry_run {
+                write_package_version(&workspace_root.join("Cargo.toml"), &new_version, true)?;
+            }
+        }
+
+        if plan.is_empty() {
+            println!("No package has commits that warrant a version bump.");
+            return Ok(());
+        }
+
+        println!(
+            "Bump plan since {}:",
+            since_tag.as_deref().unwrap_or("the beginning of history")
+        );
+        for (name, old_version, new_version) in &plan {
+            println!("  {} {} -> {}", name, old_version, new_version);
+        }
+
+        if dry_run {
+            println!("Dry run: no manifest was written.");
+        }
+
+        Ok(())
+    }
+}
+
@@ -0,0 +1,66 @@
+use crate::{
+    CommandOptions,
+    errors::Result,
+    helpers::{
+        changelog::{commits_for_package, prepend_changelog, render_changelog_section},
+        conventional::{commits_since, last_release_tag},
+        workspace::PackageId,
+    },
+};
+use clap::Parser;
+use std::path::PathBuf;
+
+/// Generates (or prepends to) each package's `CHANGELOG.md` from its
+/// conventional commit history since the last release tag.
+#[derive(Debug, Parser)]
+pub struct ChangelogCommand {
+    /// The path to the workspace root directory
+    #[arg(short, long, default_value = ".")]
+    pub workspace_root: PathBuf,
+
+    /// Print the generated changelog sections to stdout instead of writing them
+    #[arg(long)]
+    pub dry_run: bool,
+}
+
+impl ChangelogCommand {
+    pub async fn run(&self, shared: &CommandOptions) -> Result<()> {
+        let workspace_root =
+            if self.workspace_root != PathBuf::from(".") { self.workspace_root.clone() } else { shared.workspace_root.clone() };
+        let dry_run = self.dry_run || shared.dry_run;
+
+        let workspace = crate::helpers::workspace::discover_workspace_packages(&workspace_root)?;
+        let since_tag = last_release_tag(&workspace_root)?;
+        let commits = commits_since(&workspace_root, since_tag.as_deref())?;
+        let date = chrono::Utc::now().format("%Y-%m-%d").to_string();
+
+        let mut ids: Vec<&PackageId> = workspace.packages.keys().collect();
+        ids.sort();
+
+        let mut updated = 0;
+        for id in ids {
+            let package = &workspace.packages[id];
+            let package_commits = commits_for_package(&commits, &workspace_root, &package.path);
+            if package_commits.is_empty() {
+                continue;
+            }
+
+            let section = render_changelog_section(&package.version, &date, &package_commits);
+
+            if dry_run {
+                println!("# {} ({})\n{}", package.name, package.path.display(), section);
+                continue;
+            }
+
+            prepend_changelog(&package.path.join("CHANGELOG.md"), &section)?;
+            println!("Updated changelog for {} v{}", package.name, package.version);
+            updated += 1;
+        }
+
+        if updated == 0 && !dry_run {
+            println!("No package has commits since {} to record.", since_tag.as_deref().unwrap_or("the beginning of history"));
+        }
+
+        Ok(())
+    }
+}
@@ -1,4 +1,4 @@
-use crate::{CargoError, CommandOptions};
+use crate::{CargoError, CommandOptions, helpers::workspace::Stability};
 use clap::Parser;
 use std::path::PathBuf;
 
@@ -24,24 +24,24 @@ impl ListCommand {
         // Perform topological sort to get the correct publish order
         let sorted_packages = crate::helpers::topo_sort::topological_sort(&workspace)?;
         
-        // Filter packages that should be published
-        let publishable_packages = crate::helpers::topo_sort::filter_publishable_packages(sorted_packages);
-        
+        // Filter packages that should be published (Deprecated included: list shows every stability level)
+        let publishable_packages = crate::helpers::topo_sort::filter_publishable_packages(sorted_packages, Stability::Deprecated);
+
         if publishable_packages.is_empty() {
             println!("No packages to publish in this workspace.");
             return Ok(());
         }
-        
+
         println!("Packages in publish order:");
         for (i, package) in publishable_packages.iter().enumerate() {
-            println!("{}. {} v{}", i + 1, package.name, package.version);
+            println!("{}. {} v{} [{}]", i + 1, package.name, package.version, package.stability);
             
             if !package.dependencies.is_empty() {
                 let workspace_deps: Vec<String> = package.dependencies.iter()
-                    .filter(|dep| publishable_packages.iter().any(|p| &p.name == dep))
-                    .cloned()
+                    .filter(|dep| publishable_packages.iter().any(|p| p.name == dep.name))
+                    .map(|dep| dep.name.clone())
                     .collect();
-                    
+
                 if !workspace_deps.is_empty() {
                     println!("   Dependencies: {}", workspace_deps.join(", "));
                 }
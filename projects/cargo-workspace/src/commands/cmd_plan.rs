@@ -0,0 +1,74 @@
+use crate::{
+    CargoError, CommandOptions,
+    helpers::{
+        checkpoint::PublishCheckpoint,
+        topo_sort::{PlanNode, build_publish_plan, filter_publishable_packages, topological_sort},
+        workspace::Stability,
+    },
+};
+use clap::Parser;
+use std::path::PathBuf;
+
+/// Renders the publish plan as a dependency tree, without invoking `cargo
+/// publish` for anything. Lets a user review exactly what `publish` (and
+/// `publish --resume`) will do, including what the checkpoint already
+/// considers done.
+#[derive(Debug, Parser)]
+pub struct PlanCommand {
+    /// The path to the workspace root directory
+    #[arg(short, long, default_value = ".")]
+    pub workspace_root: PathBuf,
+
+    /// Show packages below this stability level as skipped
+    #[arg(long, value_enum, default_value_t = Stability::Experimental)]
+    pub min_stability: Stability,
+
+    /// Show the plan as if resuming from the saved checkpoint
+    #[arg(long)]
+    pub resume: bool,
+}
+
+impl PlanCommand {
+    pub async fn run(&self, shared: &CommandOptions) -> Result<(), CargoError> {
+        let workspace_root =
+            if self.workspace_root != PathBuf::from(".") { self.workspace_root.clone() } else { shared.workspace_root.clone() };
+
+        let workspace = crate::helpers::workspace::discover_workspace_packages(&workspace_root)?;
+
+        let checkpoint = if self.resume { PublishCheckpoint::load(&workspace_root)? } else { None };
+
+        let roots = build_publish_plan(&workspace, self.min_stability, checkpoint.as_ref());
+
+        if roots.is_empty() {
+            println!("No packages in this workspace.");
+            return Ok(());
+        }
+
+        println!("Publish plan:");
+        for root in &roots {
+            print_plan_node(root, 0);
+        }
+
+        let sorted_packages = topological_sort(&workspace)?;
+        let publishable_packages = filter_publishable_packages(sorted_packages, self.min_stability);
+        let to_publish: Vec<_> = publishable_packages
+            .iter()
+            .filter(|p| !checkpoint.as_ref().map(|cp| cp.is_published(&p.name, &p.version)).unwrap_or(false))
+            .collect();
+
+        println!("\nPublish order ({} package(s) will be published):", to_publish.len());
+        for (i, package) in to_publish.iter().enumerate() {
+            println!("{}. {} v{}", i + 1, package.name, package.version);
+        }
+
+        Ok(())
+    }
+}
+
+/// Prints a [PlanNode] and its children, indented two spaces per depth.
+fn print_plan_node(node: &PlanNode, depth: usize) {
+    println!("{}{} v{} [{}]", "  ".repeat(depth), node.name, node.version, node.status);
+    for child in &node.children {
+        print_plan_node(child, depth + 1);
+    }
+}
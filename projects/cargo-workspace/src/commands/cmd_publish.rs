@@ -1,10 +1,21 @@
 use crate::{
     CommandOptions,
     errors::{CargoError, Result},
-    helpers::{checkpoint::PublishCheckpoint, workspace::CargoPackage},
+    helpers::{
+        checkpoint::PublishCheckpoint,
+        registry,
+        topo_sort::{PublishOrderGraph, resolve_dependency_among},
+        workspace::{CargoPackage, PackageId, Stability},
+    },
 };
 use clap::Parser;
-use std::{path::PathBuf, process::Command};
+use std::{
+    collections::{HashMap, HashSet},
+    path::PathBuf,
+    process::Command,
+    sync::{Arc, Mutex},
+    time::{Duration, Instant},
+};
 use tracing::{error, info, warn};
 
 #[derive(Debug, Parser)]
@@ -32,6 +43,36 @@ pub struct PublishCommand {
     /// Interval in seconds between publishing packages (default: 0)
     #[arg(long, default_value = "0")]
     pub publish_interval: u64,
+
+    /// Dry-run publish every package from a scratch copy of its manifest
+    /// before the real publish begins, catching registry-only failures early
+    #[arg(long)]
+    pub verify: bool,
+
+    /// Seconds to wait for a just-published package to appear on the registry
+    /// index before publishing its dependents (default: 300)
+    #[arg(long, default_value = "300")]
+    pub publish_timeout: u64,
+
+    /// Refuse to publish packages below this stability level
+    #[arg(long, value_enum, default_value_t = Stability::Experimental)]
+    pub min_stability: Stability,
+
+    /// Prepend a CHANGELOG.md entry for each package before publishing it
+    #[arg(long)]
+    pub changelog: bool,
+
+    /// Number of packages to publish concurrently (default: 1, strictly sequential)
+    #[arg(long, default_value = "1")]
+    pub jobs: usize,
+
+    /// Skip the pre-publish validation pass (missing metadata, unversioned
+    /// workspace path dependencies) and go straight to publishing.
+    ///
+    /// Named `--skip-validation` rather than `--no-verify` since `--verify`
+    /// already names the separate scratch-project dry-run check above.
+    #[arg(long)]
+    pub skip_validation: bool,
 }
 
 impl PublishCommand {
@@ -55,8 +96,17 @@ impl PublishCommand {
         // Perform topological sort to get the correct publish order
         let sorted_packages = crate::helpers::topo_sort::topological_sort(&workspace)?;
 
-        // Filter packages that should be published
-        let publishable_packages = crate::helpers::topo_sort::filter_publishable_packages(sorted_packages);
+        for package in &sorted_packages {
+            if package.publish && package.stability < self.min_stability {
+                warn!(
+                    "Skipping {} v{}: stability is {}, below --min-stability {}",
+                    package.name, package.version, package.stability, self.min_stability
+                );
+            }
+        }
+
+        // Filter packages that should be published and meet the stability bar
+        let publishable_packages = crate::helpers::topo_sort::filter_publishable_packages(sorted_packages, self.min_stability);
 
         if publishable_packages.is_empty() {
             println!("No packages to publish.");
@@ -100,15 +150,50 @@ impl PublishCommand {
             println!("Running in dry-run mode. No packages will be published.");
         }
 
+        if !self.skip_validation {
+            crate::helpers::validate::validate_packages(&packages_to_publish, &workspace)?;
+            println!("Validation passed for all packages.");
+        }
+
+        if self.changelog && !dry_run {
+            generate_changelogs(&workspace_root, &packages_to_publish)?;
+        }
+
+        if self.verify {
+            println!("Verifying {} packages against the registry before publishing...", packages_to_publish.len());
+            crate::helpers::verify::verify_packages(&packages_to_publish, &workspace)?;
+            println!("Verification passed for all packages.");
+        }
+
         // Publish the packages with checkpoint support
-        let result = publish_packages_with_checkpoint(
-            &packages_to_publish,
-            &mut checkpoint,
-            dry_run,
-            skip_published,
-            token.map(|s| s.as_str()),
-            self.publish_interval
-        );
+        let result = if self.jobs > 1 {
+            let checkpoint_arc =
+                Arc::new(Mutex::new(std::mem::replace(&mut checkpoint, PublishCheckpoint::new(workspace_root.clone()))));
+            let owned_packages: Vec<CargoPackage> = packages_to_publish.iter().map(|p| (*p).clone()).collect();
+            let outcome = publish_packages_concurrently(
+                &owned_packages,
+                checkpoint_arc.clone(),
+                dry_run,
+                skip_published,
+                token.map(|s| s.to_string()),
+                Duration::from_secs(self.publish_timeout),
+                self.jobs,
+            )
+            .await;
+            checkpoint =
+                Arc::try_unwrap(checkpoint_arc).expect("no other owners left").into_inner().expect("checkpoint mutex poisoned");
+            outcome
+        } else {
+            publish_packages_with_checkpoint(
+                &packages_to_publish,
+                &mut checkpoint,
+                dry_run,
+                skip_published,
+                token.map(|s| s.as_str()),
+                self.publish_interval,
+                Duration::from_secs(self.publish_timeout),
+            )
+        };
 
         match result {
             Ok(_) => {
@@ -133,6 +218,30 @@ impl PublishCommand {
     }
 }
 
+/// Prepends a `CHANGELOG.md` entry for each package about to be published,
+/// covering commits since the last release tag that touched its path.
+fn generate_changelogs(workspace_root: &std::path::Path, packages: &[&CargoPackage]) -> Result<()> {
+    use crate::helpers::changelog::{commits_for_package, prepend_changelog, render_changelog_section};
+    use crate::helpers::conventional::{commits_since, last_release_tag};
+
+    let since_tag = last_release_tag(workspace_root)?;
+    let commits = commits_since(workspace_root, since_tag.as_deref())?;
+    let date = chrono::Utc::now().format("%Y-%m-%d").to_string();
+
+    for package in packages {
+        let package_commits = commits_for_package(&commits, workspace_root, &package.path);
+        if package_commits.is_empty() {
+            continue;
+        }
+
+        let section = render_changelog_section(&package.version, &date, &package_commits);
+        prepend_changelog(&package.path.join("CHANGELOG.md"), &section)?;
+        info!("Updated changelog for {} v{}", package.name, package.version);
+    }
+
+    Ok(())
+}
+
 /// Publishes a single package using cargo publish
 pub fn publish_package(package: &CargoPackage, dry_run: bool, token: Option<&str>) -> Result<()> {
     info!("Publishing package: {} v{}", package.name, package.version);
@@ -178,34 +287,61 @@ pub fn publish_package(package: &CargoPackage, dry_run: bool, token: Option<&str
     }
 }
 
-/// Checks if a package is already published
+/// Checks whether the exact `package.name@package.version` is already
+/// published, by querying the sparse registry index directly rather than
+/// fuzzy-matching `cargo search` output. Only a genuine network failure
+/// falls back to "assume not published"; a crate that exists on the index
+/// but lacks this version correctly reports `false`.
 pub fn is_package_published(package: &CargoPackage) -> Result<bool> {
-    info!("Checking if package {} is already published", package.name);
-
-    let mut cmd = Command::new("cargo");
-    cmd.arg("search");
-    cmd.arg(&package.name);
-    cmd.arg("--limit");
-    cmd.arg("1");
+    info!("Checking if {} v{} is already published", package.name, package.version);
 
-    let output = cmd.output()?;
-
-    if output.status.success() {
-        let stdout = String::from_utf8_lossy(&output.stdout);
-        // Check if the package exists in the registry
-        if stdout.contains(&package.name) {
-            info!("Package {} is already published", package.name);
-            Ok(true)
+    match registry::is_version_indexed(&package.name, &package.version) {
+        Ok(found) => {
+            if found {
+                info!("{} v{} is already published", package.name, package.version);
+            }
+            else {
+                info!("{} v{} is not published yet", package.name, package.version);
+            }
+            Ok(found)
         }
-        else {
-            info!("Package {} is not published yet", package.name);
+        Err(e) => {
+            warn!("Failed to check registry index for {}: {}, assuming it's not published", package.name, e);
             Ok(false)
         }
     }
-    else {
-        // If search fails, assume the package is not published
-        warn!("Failed to check if package {} is published, assuming it's not", package.name);
-        Ok(false)
+}
+
+/// Waits for a just-published package to become visible on the registry
+/// index, polling the sparse crates.io index for the exact `name@version`
+/// with exponential backoff (starting at 2s, capped at 60s between checks)
+/// until `timeout` elapses.
+pub fn wait_for_index(package: &CargoPackage, timeout: Duration) -> Result<()> {
+    let start = Instant::now();
+    let mut delay = Duration::from_secs(2);
+
+    loop {
+        match registry::is_version_indexed(&package.name, &package.version) {
+            Ok(true) => {
+                info!("{} v{} is now visible on the registry index", package.name, package.version);
+                return Ok(());
+            }
+            Ok(false) => {}
+            Err(e) => warn!("Failed to check registry index for {}: {}", package.name, e),
+        }
+
+        let elapsed = start.elapsed();
+        if elapsed >= timeout {
+            return Err(CargoError::PublishError(format!(
+                "Timed out after {:?} waiting for {} v{} to appear on the registry index",
+                timeout, package.name, package.version
+            )));
+        }
+
+        let wait = delay.min(timeout - elapsed);
+        info!("{} v{} not yet indexed, retrying in {:?}...", package.name, package.version, wait);
+        std::thread::sleep(wait);
+        delay = (delay * 2).min(Duration::from_secs(60));
     }
 }
 
@@ -216,7 +352,8 @@ pub fn publish_packages_with_checkpoint(
     dry_run: bool,
     skip_published: bool,
     token: Option<&str>,
-    publish_interval: u64
+    publish_interval: u64,
+    publish_timeout: Duration,
 ) -> Result<()> {
     for (index, package) in packages.iter().enumerate() {
         if skip_published {
@@ -243,11 +380,17 @@ pub fn publish_packages_with_checkpoint(
                 // Mark as published in checkpoint
                 checkpoint.mark_published(package.name.clone(), package.version.clone());
                 checkpoint.save()?;
-                
-                // If this is not the last package and not in dry-run mode, wait for the interval
-                if index < packages.len() - 1 && !dry_run && publish_interval > 0 {
-                    println!("Waiting {} seconds before publishing next package...", publish_interval);
-                    std::thread::sleep(std::time::Duration::from_secs(publish_interval));
+
+                let is_last = index == packages.len() - 1;
+                if !dry_run && !is_last {
+                    // Don't let a dependent publish against an index that
+                    // hasn't caught up with the package we just uploaded.
+                    wait_for_index(package, publish_timeout)?;
+
+                    if publish_interval > 0 {
+                        println!("Waiting {} seconds before publishing next package...", publish_interval);
+                        std::thread::sleep(std::time::Duration::from_secs(publish_interval));
+                    }
                 }
             }
             Err(e) => {
@@ -260,6 +403,122 @@ pub fn publish_packages_with_checkpoint(
     Ok(())
 }
 
+/// Publishes packages concurrently, honoring workspace dependency order via a
+/// [PublishOrderGraph] and capping in-flight publishes at `jobs`. A failure
+/// only blocks that package's transitive dependents; independent subtrees
+/// already in flight are left to finish.
+pub async fn publish_packages_concurrently(
+    packages: &[CargoPackage],
+    checkpoint: Arc<Mutex<PublishCheckpoint>>,
+    dry_run: bool,
+    skip_published: bool,
+    token: Option<String>,
+    publish_timeout: Duration,
+    jobs: usize,
+) -> Result<()> {
+    let jobs = jobs.max(1);
+    let by_id: HashMap<PackageId, CargoPackage> = packages.iter().map(|p| (p.id(), p.clone())).collect();
+    let mut graph = PublishOrderGraph::new(packages);
+    let mut in_flight: HashSet<PackageId> = HashSet::new();
+    let mut failed: HashSet<PackageId> = HashSet::new();
+    let mut errors: Vec<String> = Vec::new();
+    let mut tasks: tokio::task::JoinSet<(PackageId, Result<()>)> = tokio::task::JoinSet::new();
+
+    while !graph.is_empty() || !tasks.is_empty() {
+        // Resolve (without publishing) anything whose dependencies already failed,
+        // so the cascade doesn't block independent subtrees.
+        for id in graph.next() {
+            if in_flight.contains(&id) {
+                continue;
+            }
+            let package = &by_id[&id];
+            if package
+                .dependencies
+                .iter()
+                .any(|dep| resolve_dependency_among(packages, dep).map(|dep_package| failed.contains(&dep_package.id())).unwrap_or(false))
+            {
+                warn!("Skipping {}: a workspace dependency failed to publish", id);
+                failed.insert(id.clone());
+                graph.mark_resolved(&id);
+            }
+        }
+
+        // Spawn newly-ready packages up to the job limit.
+        for id in graph.next() {
+            if in_flight.len() >= jobs {
+                break;
+            }
+            if in_flight.contains(&id) || failed.contains(&id) {
+                continue;
+            }
+
+            let package = by_id[&id].clone();
+            let checkpoint = checkpoint.clone();
+            let token = token.clone();
+            in_flight.insert(id.clone());
+
+            tasks.spawn_blocking(move || {
+                let result = publish_one(&package, &checkpoint, dry_run, skip_published, token.as_deref(), publish_timeout);
+                (package.id(), result)
+            });
+        }
+
+        let Some(joined) = tasks.join_next().await else { continue };
+        let (id, result) = joined.map_err(|e| CargoError::PublishError(format!("publish task panicked: {}", e)))?;
+        in_flight.remove(&id);
+        graph.mark_resolved(&id);
+
+        if let Err(e) = result {
+            failed.insert(id.clone());
+            errors.push(format!("{}: {}", id, e));
+        }
+    }
+
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(CargoError::PublishError(format!("{} package(s) failed to publish:\n{}", errors.len(), errors.join("\n"))))
+    }
+}
+
+/// Publishes (or skips, if already published) a single package and waits for
+/// it to land on the registry index, updating the shared checkpoint.
+fn publish_one(
+    package: &CargoPackage,
+    checkpoint: &Mutex<PublishCheckpoint>,
+    dry_run: bool,
+    skip_published: bool,
+    token: Option<&str>,
+    publish_timeout: Duration,
+) -> Result<()> {
+    if skip_published {
+        match is_package_published(package) {
+            Ok(true) => {
+                info!("Skipping already published package: {}", package.name);
+                let mut checkpoint = checkpoint.lock().unwrap();
+                checkpoint.mark_published(package.name.clone(), package.version.clone());
+                return checkpoint.save();
+            }
+            Ok(false) => {}
+            Err(e) => warn!("Failed to check if package {} is published: {}, proceeding with publish", package.name, e),
+        }
+    }
+
+    publish_package(package, dry_run, token)?;
+
+    {
+        let mut checkpoint = checkpoint.lock().unwrap();
+        checkpoint.mark_published(package.name.clone(), package.version.clone());
+        checkpoint.save()?;
+    }
+
+    if !dry_run {
+        wait_for_index(package, publish_timeout)?;
+    }
+
+    Ok(())
+}
+
 /// Publishes packages in order, skipping already published ones (legacy function without checkpoint)
 pub fn publish_packages(packages: &[CargoPackage], dry_run: bool, skip_published: bool, token: Option<&str>) -> Result<()> {
     for package in packages {
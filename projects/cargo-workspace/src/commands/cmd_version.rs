@@ -0,0 +1,116 @@
+use crate::{
+    CommandOptions,
+    errors::Result,
+    helpers::{
+        conventional::{BumpLevel, apply_bump, bump_level_for_package, commits_since, last_release_tag, supersedes_pending_version, workspace_version_bump_level},
+        topo_sort::topological_sort,
+        workspace::{write_dependency_requirement, write_package_version},
+    },
+};
+use clap::Parser;
+use std::{collections::HashMap, path::PathBuf};
+
+/// Like [crate::commands::BumpCommand], but walks packages in topological
+/// (dependency-first) order and propagates each bump: a dependent of a
+/// bumped workspace dependency receives at least a patch bump itself, and
+/// its `[dependencies]` requirement on that dependency is rewritten to match.
+#[derive(Debug, Parser)]
+pub struct VersionCommand {
+    /// The path to the workspace root directory
+    #[arg(short, long, default_value = ".")]
+    pub workspace_root: PathBuf,
+
+    /// Compute and print the version plan without writing any manifest
+    #[arg(long)]
+    pub dry_run: bool,
+}
+
+impl VersionCommand {
+    pub async fn run(&self, shared: &CommandOptions) -> Result<()> {
+        let workspace_root =
+            if self.workspace_root != PathBuf::from(".") { self.workspace_root.clone() } else { shared.workspace_root.clone() };
+        let dry_run = self.dry_run || shared.dry_run;
+
+        let workspace = crate::helpers::workspace::discover_workspace_packages(&workspace_root)?;
+        let since_tag = last_release_tag(&workspace_root)?;
+        let commits = commits_since(&workspace_root, since_tag.as_deref())?;
+
+        // Dependency-first order, so a dependency's new version is already
+        // known by the time we consider its dependents.
+        let sorted_packages = topological_sort(&workspace)?;
+
+        let mut new_versions: HashMap<String, String> = HashMap::new();
+        let mut plan: Vec<(String, String, String)> = Vec::new();
+        let mut dependency_updates: Vec<(String, String, String)> = Vec::new();
+
+        // `[workspace.package] version` is a single shared value: a package's
+        // own commits can only ever raise it, never fall below what another
+        // inheriting package's commits already demand. Compute that floor
+        // across every inheriting package up front so whichever one is
+        // processed first in topological order doesn't under-bump it.
+        let workspace_commit_level = workspace_version_bump_level(sorted_packages.iter(), &commits, &workspace_root);
+
+        let mut workspace_write: Option<String> = None;
+
+        for package in &sorted_packages {
+            let mut level = bump_level_for_package(&commits, &workspace_root, &package.path);
+            if package.inherits_workspace_version {
+                level = level.max(workspace_commit_level);
+            }
+
+            let bumped_deps: Vec<(&str, &str)> =
+                package.dependencies.iter().filter_map(|dep| new_versions.get(&dep.name).map(|v| (dep.name.as_str(), v.as_str()))).collect();
+
+            if level == BumpLevel::None && !bumped_deps.is_empty() {
+                level = BumpLevel::Patch;
+            }
+
+            if level != BumpLevel::None {
+                let new_version = apply_bump(&package.version, level)?;
+                plan.push((package.name.clone(), package.version.clone(), new_version.clone()));
+                new_versions.insert(package.name.clone(), new_version.clone());
+
+                if !dry_run {
+                    if package.inherits_workspace_version {
+                        if supersedes_pending_version(workspace_write.as_deref(), &new_version) {
+                            workspace_write = Some(new_version);
+                        }
+                    } else {
+                        write_package_version(&package.path.join("Cargo.toml"), &new_version, false)?;
+                    }
+                }
+            }
+
+            for (dep_name, dep_version) in bumped_deps {
+                dependency_updates.push((package.name.clone(), dep_name.to_string(), dep_version.to_string()));
+
+                if !dry_run {
+                    write_dependency_requirement(&package.path.join("Cargo.toml"), dep_name, dep_version)?;
+                }
+            }
+        }
+
+        if let Some(new_version) = workspace_write {
+            write_package_version(&workspace_root.join("Cargo.toml"), &new_version, true)?;
+        }
+
+        if plan.is_empty() {
+            println!("No package has commits that warrant a version bump.");
+            return Ok(());
+        }
+
+        println!("Version plan since {}:", since_tag.as_deref().unwrap_or("the beginning of history"));
+        for (name, old_version, new_version) in &plan {
+            println!("  {} {} -> {}", name, old_version, new_version);
+        }
+        for (name, dep_name, dep_version) in &dependency_updates {
+            println!("  {} requires {} {}", name, dep_name, dep_version);
+        }
+
+        if dry_run {
+            println!("Dry run: no manifest was written.");
+        }
+
+        Ok(())
+    }
+}
@@ -1,20 +1,36 @@
-use crate::{commands::cmd_publish::PublishCommand, CargoError, CargoWorkspaceCommand, CommandOptions};
+use crate::{commands::cmd_publish::PublishCommand, helpers::workspace::Stability, CargoError, CargoWorkspaceCommand, CommandOptions};
 use clap::Subcommand;
 
+mod cmd_bump;
+mod cmd_changelog;
 mod cmd_list;
+mod cmd_plan;
 mod cmd_publish;
+mod cmd_version;
 
 pub use self::{
+    cmd_bump::BumpCommand,
+    cmd_changelog::ChangelogCommand,
     cmd_list::ListCommand,
+    cmd_plan::PlanCommand,
     cmd_publish::{is_package_published, publish_package, publish_packages},
+    cmd_version::VersionCommand,
 };
 
 #[derive(Debug, Subcommand)]
 pub enum WorkspaceCommands {
     /// List all packages in the workspace in publish order
     List(ListCommand),
+    /// Render the publish plan as a dependency tree without publishing anything
+    Plan(PlanCommand),
     /// Publish all packages in the workspace
     Publish(PublishCommand),
+    /// Compute per-package version bumps from conventional commit history
+    Bump(BumpCommand),
+    /// Compute version bumps in dependency order and update dependents' version requirements
+    Version(VersionCommand),
+    /// Generate per-package CHANGELOG.md entries from commit history
+    Changelog(ChangelogCommand),
 }
 
 impl CargoWorkspaceCommand {
@@ -37,18 +53,18 @@ impl CargoWorkspaceCommand {
         let sorted_packages = crate::helpers::topo_sort::topological_sort(&workspace)?;
         
         // Filter packages that should be published
-        let publishable_packages = crate::helpers::topo_sort::filter_publishable_packages(sorted_packages);
-        
+        let publishable_packages = crate::helpers::topo_sort::filter_publishable_packages(sorted_packages, Stability::Deprecated);
+
         println!("Cargo Workspace Information");
         println!("=========================");
         println!("Workspace Root: {}", workspace.root.display());
         println!("Total Packages: {}", workspace.packages.len());
         println!("Publishable Packages: {}", publishable_packages.len());
-        
+
         if !publishable_packages.is_empty() {
             println!("\nPackages in publish order:");
             for (i, package) in publishable_packages.iter().enumerate() {
-                println!("{}. {} v{}", i + 1, package.name, package.version);
+                println!("{}. {} v{} [{}]", i + 1, package.name, package.version, package.stability);
             }
         }
         
@@ -63,7 +79,11 @@ impl WorkspaceCommands {
     pub async fn run(&self, shared: &CommandOptions) -> Result<(), CargoError> {
         match self {
             WorkspaceCommands::List(cmd) => cmd.run(shared).await,
+            WorkspaceCommands::Plan(cmd) => cmd.run(shared).await,
             WorkspaceCommands::Publish(cmd) => cmd.run(shared).await,
+            WorkspaceCommands::Bump(cmd) => cmd.run(shared).await,
+            WorkspaceCommands::Version(cmd) => cmd.run(shared).await,
+            WorkspaceCommands::Changelog(cmd) => cmd.run(shared).await,
         }
     }
 }
\ No newline at end of file
@@ -11,6 +11,8 @@ impl Display for CargoError {
             CargoError::PublishError(msg) => write!(f, "Publish error: {}", msg),
             CargoError::DependencyError(msg) => write!(f, "Dependency error: {}", msg),
             CargoError::CircularDependency(msg) => write!(f, "Circular dependency: {}", msg),
+            CargoError::GitError(msg) => write!(f, "Git error: {}", msg),
+            CargoError::ValidationError(problems) => write!(f, "Pre-publish validation failed:\n{}", problems.join("\n")),
         }
     }
 }
@@ -19,6 +19,8 @@ pub enum CargoError {
     PublishError(String),
     DependencyError(String),
     CircularDependency(String),
+    GitError(String),
+    ValidationError(Vec<String>),
 }
 
 impl From<io::Error> for CargoError {
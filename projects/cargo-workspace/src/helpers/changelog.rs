@@ -0,0 +1,68 @@
+use crate::{
+    errors::Result,
+    helpers::conventional::{classify_commit, BumpLevel, ConventionalCommit},
+};
+use std::{fs, path::Path};
+
+/// Filters commits down to the ones that touched `package_path`.
+pub fn commits_for_package<'a>(
+    commits: &'a [ConventionalCommit],
+    workspace_root: &Path,
+    package_path: &Path,
+) -> Vec<&'a ConventionalCommit> {
+    let relative = package_path.strip_prefix(workspace_root).unwrap_or(package_path);
+    commits.iter().filter(|commit| commit.files.iter().any(|file| file.starts_with(relative))).collect()
+}
+
+/// Renders a Markdown section for one release, grouping commits into
+/// Breaking Changes / Features / Bug Fixes / Other by their conventional
+/// commit type.
+pub fn render_changelog_section(version: &str, date: &str, commits: &[&ConventionalCommit]) -> String {
+    let mut breaking = Vec::new();
+    let mut features = Vec::new();
+    let mut fixes = Vec::new();
+    let mut other = Vec::new();
+
+    for commit in commits {
+        let short_hash = &commit.hash[..commit.hash.len().min(7)];
+        let line = format!("- {} ({})", commit.subject, short_hash);
+
+        match classify_commit(&commit.subject, &commit.body) {
+            BumpLevel::Major => breaking.push(line),
+            BumpLevel::Minor => features.push(line),
+            BumpLevel::Patch => fixes.push(line),
+            BumpLevel::None => other.push(line),
+        }
+    }
+
+    let mut section = format!("## {} - {}\n\n", version, date);
+    for (title, lines) in [("Breaking Changes", &breaking), ("Features", &features), ("Bug Fixes", &fixes), ("Other", &other)] {
+        if lines.is_empty() {
+            continue;
+        }
+        section.push_str(&format!("### {}\n\n", title));
+        for line in lines {
+            section.push_str(line);
+            section.push('\n');
+        }
+        section.push('\n');
+    }
+
+    section
+}
+
+/// Prepends a rendered section to `changelog_path`, creating the file with a
+/// top-level heading if it doesn't exist yet.
+pub fn prepend_changelog(changelog_path: &Path, section: &str) -> Result<()> {
+    let existing = fs::read_to_string(changelog_path).unwrap_or_default();
+
+    let mut content = String::new();
+    if existing.is_empty() {
+        content.push_str("# Changelog\n\n");
+    }
+    content.push_str(section);
+    content.push_str(&existing);
+
+    fs::write(changelog_path, content)?;
+    Ok(())
+}
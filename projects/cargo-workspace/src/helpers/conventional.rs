@@ -0,0 +1,213 @@
+use crate::{
+    errors::{CargoError, Result},
+    helpers::workspace::CargoPackage,
+};
+use std::{
+    path::{Path, PathBuf},
+    process::Command,
+};
+
+/// The kind of semantic version bump implied by a set of commits, following
+/// the [Conventional Commits](https://www.conventionalcommits.org/) spec.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum BumpLevel {
+    /// No commit touching the package matched a recognised prefix.
+    None,
+    /// A `fix:` commit.
+    Patch,
+    /// A `feat:` commit.
+    Minor,
+    /// A `!` marker after the type, or a `BREAKING CHANGE:` footer.
+    Major,
+}
+
+/// A single commit, together with the paths it touched relative to the
+/// workspace root.
+#[derive(Debug, Clone)]
+pub struct ConventionalCommit {
+    pub hash: String,
+    pub subject: String,
+    pub body: String,
+    pub files: Vec<PathBuf>,
+}
+
+/// Classifies a commit subject/body pair into a [BumpLevel].
+pub fn classify_commit(subject: &str, body: &str) -> BumpLevel {
+    if body.contains("BREAKING CHANGE:") {
+        return BumpLevel::Major;
+    }
+
+    let Some((kind, _)) = subject.split_once(':') else {
+        return BumpLevel::None;
+    };
+
+    let kind = kind.trim();
+    let (kind, breaking) = match kind.strip_suffix('!') {
+        Some(stripped) => (stripped, true),
+        None => (kind, false),
+    };
+
+    // Strip an optional `(scope)` between the type and the `!`/`:`, e.g. `fix(parser)`.
+    let kind = match kind.find('(') {
+        Some(paren) if kind.ends_with(')') => &kind[..paren],
+        _ => kind,
+    };
+
+    if breaking {
+        return BumpLevel::Major;
+    }
+
+    match kind {
+        "fix" => BumpLevel::Patch,
+        "feat" => BumpLevel::Minor,
+        _ => BumpLevel::None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn classifies_scoped_commits() {
+        assert_eq!(classify_commit("fix(parser): handle empty input", ""), BumpLevel::Patch);
+        assert_eq!(classify_commit("feat(cli): add --verbose flag", ""), BumpLevel::Minor);
+        assert_eq!(classify_commit("feat(cli)!: drop legacy flag", ""), BumpLevel::Major);
+        assert_eq!(classify_commit("fix: unscoped patch", ""), BumpLevel::Patch);
+        assert_eq!(classify_commit("chore(deps): bump semver", ""), BumpLevel::None);
+    }
+}
+
+/// Finds the most recent release tag reachable from `HEAD`, if any.
+pub fn last_release_tag(workspace_root: &Path) -> Result<Option<String>> {
+    let output = Command::new("git").args(["describe", "--tags", "--abbrev=0"]).current_dir(workspace_root).output()?;
+
+    if !output.status.success() {
+        // No tags yet; treat the whole history as unreleased.
+        return Ok(None);
+    }
+
+    let tag = String::from_utf8_lossy(&output.stdout).trim().to_string();
+    Ok(if tag.is_empty() { None } else { Some(tag) })
+}
+
+/// Collects every commit since `since_tag` (or the whole history when `None`),
+/// together with the files each commit touched.
+pub fn commits_since(workspace_root: &Path, since_tag: Option<&str>) -> Result<Vec<ConventionalCommit>> {
+    let range = match since_tag {
+        Some(tag) => format!("{}..HEAD", tag),
+        None => "HEAD".to_string(),
+    };
+
+    let output = Command::new("git").args(["log", &range, "--pretty=format:%H"]).current_dir(workspace_root).output()?;
+
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        return Err(CargoError::GitError(format!("git log failed: {}", stderr)));
+    }
+
+    let hashes = String::from_utf8_lossy(&output.stdout);
+    let mut commits = Vec::new();
+    for hash in hashes.lines().map(str::trim).filter(|h| !h.is_empty()) {
+        commits.push(commit_details(workspace_root, hash)?);
+    }
+
+    Ok(commits)
+}
+
+/// Fetches the subject, body and changed files for a single commit.
+fn commit_details(workspace_root: &Path, hash: &str) -> Result<ConventionalCommit> {
+    const FIELD_SEP: &str = "\x1f";
+
+    let output = Command::new("git")
+        .args(["show", "--name-only", &format!("--pretty=format:%s{FIELD_SEP}%b{FIELD_SEP}"), hash])
+        .current_dir(workspace_root)
+        .output()?;
+
+    if !output.status.success() {
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        return Err(CargoError::GitError(format!("git show {} failed: {}", hash, stderr)));
+    }
+
+    let text = String::from_utf8_lossy(&output.stdout);
+    let mut parts = text.splitn(3, FIELD_SEP);
+    let subject = parts.next().unwrap_or_default().trim().to_string();
+    let body = parts.next().unwrap_or_default().trim().to_string();
+    let files = parts.next().unwrap_or_default().lines().map(str::trim).filter(|l| !l.is_empty()).map(PathBuf::from).collect();
+
+    Ok(ConventionalCommit { hash: hash.to_string(), subject, body, files })
+}
+
+/// Determines the highest [BumpLevel] implied by commits that touched
+/// `package_path` (absolute, as stored on [CargoPackage](crate::helpers::workspace::CargoPackage)).
+pub fn bump_level_for_package(commits: &[ConventionalCommit], workspace_root: &Path, package_path: &Path) -> BumpLevel {
+    let relative = package_path.strip_prefix(workspace_root).unwrap_or(package_path);
+
+    commits
+        .iter()
+        .filter(|commit| commit.files.iter().any(|file| file.starts_with(relative)))
+        .map(|commit| classify_commit(&commit.subject, &commit.body))
+        .max()
+        .unwrap_or(BumpLevel::None)
+}
+
+/// Computes the bump level `[workspace.package] version` must receive: the
+/// max across every package that inherits it via `version.workspace = true`.
+/// That field is a single shared value, so no inheriting package's own
+/// commits may ever cause it to be bumped by less than this, regardless of
+/// which package a caller happens to process first. Shared by `bump` and
+/// `version`, which both have to resolve this floor before writing the root
+/// manifest.
+pub fn workspace_version_bump_level<'a>(
+    packages: impl IntoIterator<Item = &'a CargoPackage>,
+    commits: &[ConventionalCommit],
+    workspace_root: &Path,
+) -> BumpLevel {
+    packages
+        .into_iter()
+        .filter(|package| package.inherits_workspace_version)
+        .map(|package| bump_level_for_package(commits, workspace_root, &package.path))
+        .max()
+        .unwrap_or(BumpLevel::None)
+}
+
+/// Whether `candidate` is a strictly higher semver than `pending` (or there
+/// is no `pending` version yet). Used to track the single highest version
+/// computed for a shared `[workspace.package] version` write when several
+/// inheriting packages are processed in dependency (not necessarily bump
+/// order), e.g. because one was pushed past the commit-derived floor by a
+/// propagated dependency bump.
+pub fn supersedes_pending_version(pending: Option<&str>, candidate: &str) -> bool {
+    match pending {
+        Some(pending) => semver::Version::parse(candidate).ok() > semver::Version::parse(pending).ok(),
+        None => true,
+    }
+}
+
+/// Applies a [BumpLevel] to a semver version string, following the 0.x rule
+/// that a breaking change below `1.0.0` only bumps the minor component.
+pub fn apply_bump(version: &str, level: BumpLevel) -> Result<String> {
+    let mut version =
+        semver::Version::parse(version).map_err(|e| CargoError::InvalidToml(format!("invalid version `{}`: {}", version, e)))?;
+
+    match level {
+        BumpLevel::None => {}
+        BumpLevel::Patch => version.patch += 1,
+        BumpLevel::Minor => {
+            version.minor += 1;
+            version.patch = 0;
+        }
+        BumpLevel::Major => {
+            if version.major == 0 {
+                // Pre-1.0 crates treat a breaking change as a minor bump.
+                version.minor += 1;
+            } else {
+                version.major += 1;
+                version.minor = 0;
+            }
+            version.patch = 0;
+        }
+    }
+
+    Ok(version.to_string())
+}
@@ -0,0 +1,8 @@
+pub mod changelog;
+pub mod checkpoint;
+pub mod conventional;
+pub mod registry;
+pub mod topo_sort;
+pub mod validate;
+pub mod verify;
+pub mod workspace;
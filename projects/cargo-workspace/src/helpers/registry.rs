@@ -0,0 +1,65 @@
+use crate::errors::{CargoError, Result};
+
+/// Base URL of the crates.io sparse HTTP index.
+const SPARSE_INDEX_BASE: &str = "https://index.crates.io";
+
+/// Builds the sparse-index path for `name`, following crates.io's layout:
+/// 1-char names live under `1/{name}`, 2-char under `2/{name}`, 3-char under
+/// `3/{first-char}/{name}`, and everything else under `{a}{b}/{c}{d}/{name}`
+/// using the first four characters of the lowercased name.
+pub fn sparse_index_path(name: &str) -> String {
+    let lower = name.to_lowercase();
+    match lower.len() {
+        1 => format!("1/{}", lower),
+        2 => format!("2/{}", lower),
+        3 => format!("3/{}/{}", &lower[..1], lower),
+        _ => format!("{}/{}/{}", &lower[..2], &lower[2..4], lower),
+    }
+}
+
+/// Fetches and parses the sparse-index entries for `name`, returning the list
+/// of published versions. A 404 (crate never published) is reported as an
+/// empty list, not an error.
+pub fn fetch_index_versions(name: &str) -> Result<Vec<String>> {
+    let url = format!("{}/{}", SPARSE_INDEX_BASE, sparse_index_path(name));
+
+    let response = reqwest::blocking::Client::new()
+        .get(&url)
+        .header("User-Agent", "cargo-workspace")
+        .send()
+        .map_err(|e| CargoError::PublishError(format!("Failed to query registry index for {}: {}", name, e)))?;
+
+    if response.status() == reqwest::StatusCode::NOT_FOUND {
+        return Ok(Vec::new());
+    }
+
+    if !response.status().is_success() {
+        return Err(CargoError::PublishError(format!(
+            "Registry index returned {} for {}",
+            response.status(),
+            name
+        )));
+    }
+
+    let body = response
+        .text()
+        .map_err(|e| CargoError::PublishError(format!("Failed to read registry index response for {}: {}", name, e)))?;
+    Ok(parse_index_versions(&body))
+}
+
+/// Parses the newline-delimited JSON index body, pulling the `vers` field out
+/// of each line. Malformed lines are skipped rather than failing the whole
+/// lookup.
+fn parse_index_versions(body: &str) -> Vec<String> {
+    body.lines()
+        .filter(|line| !line.trim().is_empty())
+        .filter_map(|line| serde_json::from_str::<serde_json::Value>(line).ok())
+        .filter_map(|value| value.get("vers").and_then(|v| v.as_str()).map(|v| v.to_string()))
+        .collect()
+}
+
+/// Checks whether `name@version` is present in the sparse registry index.
+pub fn is_version_indexed(name: &str, version: &str) -> Result<bool> {
+    let versions = fetch_index_versions(name)?;
+    Ok(versions.iter().any(|v| v == version))
+}
@@ -1,30 +1,38 @@
 use crate::{
     errors::{CargoError, Result},
-    helpers::workspace::{CargoPackage, CargoWorkspace},
+    helpers::{
+        checkpoint::PublishCheckpoint,
+        workspace::{CargoPackage, CargoWorkspace, PackageId, Stability},
+    },
 };
 use petgraph::{Directed, Graph, algo::toposort};
-use std::collections::{HashMap, HashSet};
+use std::{
+    collections::{HashMap, HashSet},
+    fmt::{Display, Formatter},
+};
 
 /// Performs topological sort on workspace packages based on their dependencies
 pub fn topological_sort(workspace: &CargoWorkspace) -> Result<Vec<CargoPackage>> {
-    let mut graph: Graph<String, (), Directed> = Graph::new();
-    let mut node_indices: HashMap<String, petgraph::prelude::NodeIndex> = HashMap::new();
+    let mut graph: Graph<PackageId, (), Directed> = Graph::new();
+    let mut node_indices: HashMap<PackageId, petgraph::prelude::NodeIndex> = HashMap::new();
 
     // Add all packages as nodes
-    for (name, _package) in &workspace.packages {
-        let index = graph.add_node(name.clone());
-        node_indices.insert(name.clone(), index);
+    for id in workspace.packages.keys() {
+        let index = graph.add_node(id.clone());
+        node_indices.insert(id.clone(), index);
     }
 
     // Add edges based on dependencies
     // Edge direction: dependency -> dependent (so dependencies come first in topological order)
-    for (name, package) in &workspace.packages {
-        let to_index = node_indices.get(name).unwrap();
+    for (id, package) in &workspace.packages {
+        let to_index = node_indices.get(id).unwrap();
 
         for dep in &package.dependencies {
-            // Only add edges for dependencies that are also in the workspace
-            if let Some(from_index) = node_indices.get(dep) {
-                graph.add_edge(*from_index, *to_index, ());
+            // Resolve the dependency like cargo resolves a path dep: by name
+            // *and* a version satisfying the requirement, not by name alone.
+            if let Some(dep_package) = workspace.resolve_dependency(dep) {
+                let from_index = node_indices.get(&dep_package.id()).unwrap();
+                graph.add_edge(*from_index, to_index.clone(), ());
             }
         }
     }
@@ -34,14 +42,14 @@ pub fn topological_sort(workspace: &CargoWorkspace) -> Result<Vec<CargoPackage>>
         Ok(sorted_indices) => {
             let mut sorted_packages = Vec::new();
             for index in sorted_indices {
-                let package_name = &graph[index];
-                if let Some(package) = workspace.packages.get(package_name) {
+                let package_id = &graph[index];
+                if let Some(package) = workspace.packages.get(package_id) {
                     sorted_packages.push(package.clone());
                 }
             }
             Ok(sorted_packages)
         }
-        Err(cycle_error) => {
+        Err(_cycle_error) => {
             // Use petgraph's cycle detection to get the actual cycle
             use petgraph::algo::is_cyclic_directed;
             if is_cyclic_directed(&graph) {
@@ -49,16 +57,14 @@ pub fn topological_sort(workspace: &CargoWorkspace) -> Result<Vec<CargoPackage>>
                 use petgraph::algo::tarjan_scc;
                 let sccs = tarjan_scc(&graph);
                 let mut cycles = Vec::new();
-                
+
                 for scc in sccs {
                     if scc.len() > 1 {
-                        let cycle_names: Vec<String> = scc.iter()
-                            .map(|&idx| graph[idx].clone())
-                            .collect();
+                        let cycle_names: Vec<String> = scc.iter().map(|&idx| graph[idx].to_string()).collect();
                         cycles.push(cycle_names.join(" -> "));
                     }
                 }
-                
+
                 Err(CargoError::CircularDependency(format!("Circular dependencies detected: {:?}", cycles)))
             } else {
                 Err(CargoError::CircularDependency("Topological sort failed but no cycles detected".to_string()))
@@ -67,45 +73,208 @@ pub fn topological_sort(workspace: &CargoWorkspace) -> Result<Vec<CargoPackage>>
     }
 }
 
-/// Helper function to find cycles in the dependency graph
-fn find_cycles(
-    graph: &Graph<String, (), Directed>,
-    node_indices: &HashMap<String, petgraph::prelude::NodeIndex>,
-) -> Vec<String> {
-    use petgraph::visit::Dfs;
-
-    let mut cycles = Vec::new();
-    let mut visited = HashSet::new();
-
-    for (name, index) in node_indices {
-        if !visited.contains(name) {
-            let mut dfs = Dfs::new(graph, *index);
-            let mut path = Vec::new();
-            let mut path_set = HashSet::new();
-
-            while let Some(nx) = dfs.next(graph) {
-                let node_name = &graph[nx];
-
-                if path_set.contains(node_name) {
-                    // Found a cycle
-                    if let Some(pos) = path.iter().position(|n| n == node_name) {
-                        let cycle = path[pos..].join(" -> ");
-                        cycles.push(format!("{} -> {}", cycle, node_name));
-                    }
-                    break;
+/// Filters packages based on whether they should be published and whether
+/// their declared [Stability] meets `min_stability`.
+pub fn filter_publishable_packages(packages: Vec<CargoPackage>, min_stability: Stability) -> Vec<CargoPackage> {
+    packages.into_iter().filter(|p| p.publish && p.stability >= min_stability).collect()
+}
+
+/// A Kahn-style view of the publish dependency graph (keyed by [PackageId],
+/// the same name+version identity [CargoWorkspace::resolve_dependency] uses),
+/// advanced incrementally as packages finish so independent branches can be
+/// scheduled concurrently instead of strictly one at a time.
+#[derive(Debug, Clone)]
+pub struct PublishOrderGraph {
+    /// Number of not-yet-resolved workspace dependencies per package.
+    in_degree: HashMap<PackageId, usize>,
+    /// package -> its workspace dependencies within the published set
+    /// (fixed at construction; not updated as packages resolve).
+    forward: HashMap<PackageId, HashSet<PackageId>>,
+    /// dependency -> the packages that depend on it.
+    reverse: HashMap<PackageId, Vec<PackageId>>,
+    /// Longest chain of dependents below each package, used to prioritise
+    /// the critical path.
+    depth: HashMap<PackageId, usize>,
+}
+
+impl PublishOrderGraph {
+    /// Builds the graph from the set of packages that will actually be
+    /// published, in any order.
+    pub fn new(packages: &[CargoPackage]) -> Self {
+        let mut forward: HashMap<PackageId, HashSet<PackageId>> = HashMap::new();
+        let mut reverse: HashMap<PackageId, Vec<PackageId>> = HashMap::new();
+        let mut in_degree: HashMap<PackageId, usize> = HashMap::new();
+
+        for package in packages {
+            forward.entry(package.id()).or_default();
+            reverse.entry(package.id()).or_default();
+            in_degree.entry(package.id()).or_insert(0);
+        }
+
+        for package in packages {
+            for dep in &package.dependencies {
+                // Resolve by name *and* a version satisfying the requirement,
+                // the same as CargoWorkspace::resolve_dependency, so two
+                // same-named packages at different versions can't collide.
+                let Some(dep_package) = resolve_dependency_among(packages, dep) else { continue };
+                if dep_package.id() == package.id() {
+                    continue;
+                }
+                if forward.get_mut(&package.id()).unwrap().insert(dep_package.id()) {
+                    reverse.entry(dep_package.id()).or_default().push(package.id());
+                    *in_degree.get_mut(&package.id()).unwrap() += 1;
                 }
+            }
+        }
+
+        let depth = compute_dependent_depths(&reverse);
+
+        Self { in_degree, forward, reverse, depth }
+    }
 
-                path.push(node_name.clone());
-                path_set.insert(node_name.clone());
-                visited.insert(node_name.clone());
+    /// Every package whose workspace dependencies have all resolved,
+    /// deepest-chain-of-dependents first (so the critical path starts first),
+    /// ties broken by [PackageId] ordering.
+    pub fn next(&self) -> Vec<PackageId> {
+        let mut ready: Vec<&PackageId> = self.in_degree.iter().filter(|(_, degree)| **degree == 0).map(|(id, _)| id).collect();
+        ready.sort_by(|a, b| self.depth.get(*b).cmp(&self.depth.get(*a)).then_with(|| a.cmp(b)));
+        ready.into_iter().cloned().collect()
+    }
+
+    /// Marks `id` as resolved (published, skipped, or failed), decrementing
+    /// the in-degree of everything that depends on it.
+    pub fn mark_resolved(&mut self, id: &PackageId) {
+        self.in_degree.remove(id);
+        if let Some(dependents) = self.reverse.get(id) {
+            for dependent in dependents.clone() {
+                if let Some(degree) = self.in_degree.get_mut(&dependent) {
+                    *degree = degree.saturating_sub(1);
+                }
             }
         }
     }
 
-    cycles
+    /// True once every package has been resolved.
+    pub fn is_empty(&self) -> bool {
+        self.in_degree.is_empty()
+    }
+}
+
+/// Resolves an intra-workspace dependency against a slice of packages the
+/// same way [CargoWorkspace::resolve_dependency] does against the whole
+/// workspace: by name, picking the one whose version satisfies the
+/// requirement.
+pub fn resolve_dependency_among<'a>(packages: &'a [CargoPackage], dep: &crate::helpers::workspace::Dependency) -> Option<&'a CargoPackage> {
+    packages.iter().find(|package| {
+        package.name == dep.name
+            && semver::Version::parse(&package.version).map(|v| dep.version_req.matches(&v)).unwrap_or(false)
+    })
 }
 
-/// Filters packages based on whether they should be published
-pub fn filter_publishable_packages(packages: Vec<CargoPackage>) -> Vec<CargoPackage> {
-    packages.into_iter().filter(|p| p.publish).collect()
+/// Computes, for every package, the longest chain of dependents reachable
+/// below it (memoized DFS over the reverse edges).
+fn compute_dependent_depths(reverse: &HashMap<PackageId, Vec<PackageId>>) -> HashMap<PackageId, usize> {
+    fn dfs(id: &PackageId, reverse: &HashMap<PackageId, Vec<PackageId>>, memo: &mut HashMap<PackageId, usize>) -> usize {
+        if let Some(&depth) = memo.get(id) {
+            return depth;
+        }
+        // Insert a placeholder first so a (shouldn't-happen) cycle can't recurse forever.
+        memo.insert(id.clone(), 0);
+        let depth = reverse.get(id).map(|deps| deps.iter().map(|dep| 1 + dfs(dep, reverse, memo)).max().unwrap_or(0)).unwrap_or(0);
+        memo.insert(id.clone(), depth);
+        depth
+    }
+
+    let mut memo = HashMap::new();
+    for id in reverse.keys() {
+        dfs(id, reverse, &mut memo);
+    }
+    memo
+}
+
+/// What the publish scheduler will actually do with a package, as shown by
+/// the `plan` subcommand.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PlanStatus {
+    /// Already recorded as published in the resume checkpoint.
+    AlreadyPublished,
+    /// Excluded from publishing: `publish = false` or below `--min-stability`.
+    Skipped,
+    /// Not yet published; this run will publish it.
+    WillPublish,
+}
+
+impl Display for PlanStatus {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            PlanStatus::AlreadyPublished => write!(f, "already published"),
+            PlanStatus::Skipped => write!(f, "skipped"),
+            PlanStatus::WillPublish => write!(f, "will publish"),
+        }
+    }
+}
+
+/// One node of the tree built by [build_publish_plan]: a package plus its
+/// resolved workspace dependencies nested beneath it.
+#[derive(Debug, Clone)]
+pub struct PlanNode {
+    pub name: String,
+    pub version: String,
+    pub status: PlanStatus,
+    pub children: Vec<PlanNode>,
+}
+
+/// Builds the publish plan as a forest of trees, one root per package that no
+/// other workspace member depends on, with its transitive workspace
+/// dependencies nested beneath it. Used by the `plan` subcommand.
+pub fn build_publish_plan(
+    workspace: &CargoWorkspace,
+    min_stability: Stability,
+    checkpoint: Option<&PublishCheckpoint>,
+) -> Vec<PlanNode> {
+    let mut depended_upon: HashSet<PackageId> = HashSet::new();
+    for package in workspace.packages.values() {
+        for dep in &package.dependencies {
+            if let Some(dep_package) = workspace.resolve_dependency(dep) {
+                depended_upon.insert(dep_package.id());
+            }
+        }
+    }
+
+    let mut roots: Vec<&CargoPackage> = workspace.packages.values().filter(|p| !depended_upon.contains(&p.id())).collect();
+    roots.sort_by(|a, b| a.name.cmp(&b.name));
+
+    roots.into_iter().map(|root| build_plan_node(workspace, root, min_stability, checkpoint, &mut HashSet::new())).collect()
+}
+
+/// Recursively builds a single [PlanNode], guarding against cycles with
+/// `ancestors` (a normal workspace graph is acyclic, but this keeps a stray
+/// one from recursing forever).
+fn build_plan_node(
+    workspace: &CargoWorkspace,
+    package: &CargoPackage,
+    min_stability: Stability,
+    checkpoint: Option<&PublishCheckpoint>,
+    ancestors: &mut HashSet<PackageId>,
+) -> PlanNode {
+    let status = if !package.publish || package.stability < min_stability {
+        PlanStatus::Skipped
+    } else if checkpoint.map(|cp| cp.is_published(&package.name, &package.version)).unwrap_or(false) {
+        PlanStatus::AlreadyPublished
+    } else {
+        PlanStatus::WillPublish
+    };
+
+    let id = package.id();
+    let mut children = Vec::new();
+    if ancestors.insert(id.clone()) {
+        for dep in &package.dependencies {
+            if let Some(dep_package) = workspace.resolve_dependency(dep) {
+                children.push(build_plan_node(workspace, dep_package, min_stability, checkpoint, ancestors));
+            }
+        }
+        ancestors.remove(&id);
+    }
+
+    PlanNode { name: package.name.clone(), version: package.version.clone(), status, children }
 }
@@ -0,0 +1,43 @@
+use crate::{
+    errors::{CargoError, Result},
+    helpers::workspace::{CargoPackage, CargoWorkspace},
+};
+
+/// Checks every package about to be published for the common failures that
+/// otherwise only surface mid-run as a `cargo publish` error, leaving a
+/// partial, checkpoint-saved workspace: missing crates.io-required metadata,
+/// and workspace dependencies declared as a bare `path =` without a `version`
+/// (which breaks once `path` is stripped from the uploaded manifest).
+pub fn validate_packages(packages: &[&CargoPackage], workspace: &CargoWorkspace) -> Result<()> {
+    let mut problems = Vec::new();
+
+    for package in packages {
+        problems.extend(validate_package(package, workspace));
+    }
+
+    if problems.is_empty() { Ok(()) } else { Err(CargoError::ValidationError(problems)) }
+}
+
+/// Collects every validation problem found for a single package.
+fn validate_package(package: &CargoPackage, workspace: &CargoWorkspace) -> Vec<String> {
+    let mut problems = Vec::new();
+
+    if package.description.as_deref().unwrap_or("").trim().is_empty() {
+        problems.push(format!("{}: missing `description`, required by crates.io", package.name));
+    }
+
+    if package.license.as_deref().unwrap_or("").trim().is_empty() && package.license_file.as_deref().unwrap_or("").trim().is_empty() {
+        problems.push(format!("{}: missing `license` or `license-file`, required by crates.io", package.name));
+    }
+
+    for dep in &package.dependencies {
+        if !dep.has_explicit_version && workspace.resolve_dependency(dep).is_some() {
+            problems.push(format!(
+                "{}: dependency `{}` has no `version` requirement; a bare path dependency is stripped on publish and will break the uploaded crate",
+                package.name, dep.name
+            ));
+        }
+    }
+
+    problems
+}
@@ -0,0 +1,154 @@
+use crate::{
+    errors::{CargoError, Result},
+    helpers::workspace::{CargoPackage, CargoWorkspace},
+};
+use std::{
+    fs,
+    path::{Path, PathBuf},
+    process::Command,
+};
+use tracing::{info, warn};
+
+/// Runs `cargo publish --dry-run` for each package inside a scratch copy of
+/// its manifest, with every workspace/path dependency rewritten to the exact
+/// version being published (mirroring the temp-project technique used by
+/// cargo-outdated). This catches "works locally, breaks on crates.io"
+/// failures before the real, unresumable publish begins.
+///
+/// A dependency that is itself one of `packages` (i.e. part of this same
+/// publish run) is patched back to its real on-disk path via
+/// `[patch.crates-io]`: its new version genuinely isn't on the registry yet,
+/// so a plain version requirement would make dependency resolution fail for
+/// every dependent of a bumped sibling, even though the real publish (run
+/// dependency-first) will succeed once that sibling has landed.
+pub fn verify_packages(packages: &[&CargoPackage], workspace: &CargoWorkspace) -> Result<()> {
+    let mut failures = Vec::new();
+
+    for package in packages {
+        info!("Verifying {} v{} in a scratch project", package.name, package.version);
+        if let Err(e) = verify_package(package, packages, workspace) {
+            warn!("Verification failed for {}: {}", package.name, e);
+            failures.push(format!("{} v{}: {}", package.name, package.version, e));
+        }
+    }
+
+    if failures.is_empty() {
+        Ok(())
+    } else {
+        Err(CargoError::PublishError(format!(
+            "Pre-publish verification failed for {} package(s):\n{}",
+            failures.len(),
+            failures.join("\n")
+        )))
+    }
+}
+
+/// Verifies a single package in an isolated temporary directory.
+fn verify_package(package: &CargoPackage, run: &[&CargoPackage], workspace: &CargoWorkspace) -> Result<()> {
+    let temp_dir = tempfile::tempdir().map_err(|e| CargoError::IoError(e.to_string()))?;
+
+    copy_package_into(package, temp_dir.path())?;
+    let manifest_path = temp_dir.path().join("Cargo.toml");
+    rewrite_package_version(package, &manifest_path)?;
+    rewrite_workspace_dependencies(package, run, workspace, &manifest_path)?;
+
+    let lockfile_status = Command::new("cargo").arg("generate-lockfile").current_dir(temp_dir.path()).status()?;
+    if !lockfile_status.success() {
+        return Err(CargoError::PublishError("cargo generate-lockfile failed in the scratch project".to_string()));
+    }
+
+    let output = Command::new("cargo").args(["publish", "--dry-run", "--allow-dirty"]).current_dir(temp_dir.path()).output()?;
+
+    if output.status.success() {
+        Ok(())
+    } else {
+        Err(CargoError::PublishError(String::from_utf8_lossy(&output.stderr).trim().to_string()))
+    }
+}
+
+/// Copies every file of the package's directory (other than `target/`) into `dest`.
+fn copy_package_into(package: &CargoPackage, dest: &Path) -> Result<()> {
+    for entry in walkdir::WalkDir::new(&package.path).into_iter().filter_entry(|entry| entry.file_name() != "target") {
+        let entry = entry.map_err(|e| CargoError::IoError(e.to_string()))?;
+        let relative = entry.path().strip_prefix(&package.path).unwrap_or(entry.path());
+        let target = dest.join(relative);
+
+        if entry.file_type().is_dir() {
+            fs::create_dir_all(&target)?;
+        } else {
+            if let Some(parent) = target.parent() {
+                fs::create_dir_all(parent)?;
+            }
+            fs::copy(entry.path(), &target)?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Rewrites the copied manifest's own `[package] version` to a literal string
+/// when it was declared as `version.workspace = true`. The scratch copy has
+/// no enclosing `[workspace]` to inherit from, so `cargo generate-lockfile`
+/// and `cargo publish --dry-run` would otherwise fail on missing workspace
+/// context unrelated to real publishability.
+fn rewrite_package_version(package: &CargoPackage, manifest_path: &Path) -> Result<()> {
+    if !package.inherits_workspace_version {
+        return Ok(());
+    }
+
+    let content = fs::read_to_string(manifest_path)?;
+    let mut document = content.parse::<toml_edit::DocumentMut>().map_err(|e| CargoError::InvalidToml(e.to_string()))?;
+
+    if let Some(table) = document.get_mut("package").and_then(|item| item.as_table_like_mut()) {
+        table.insert("version", toml_edit::value(package.version.clone()));
+    }
+
+    fs::write(manifest_path, document.to_string())?;
+    Ok(())
+}
+
+/// Rewrites every dependency that points at a workspace member (via `path =`
+/// or `workspace = true`) to a plain `"x.y.z"` requirement on the version
+/// being published (replacing the whole dependency entry), the way it will
+/// look once the dependency itself has been published. A dependency that is
+/// itself part of `run` is additionally patched back to its real path, since
+/// its new version isn't on the registry yet.
+fn rewrite_workspace_dependencies(package: &CargoPackage, run: &[&CargoPackage], workspace: &CargoWorkspace, manifest_path: &Path) -> Result<()> {
+    let content = fs::read_to_string(manifest_path)?;
+    let mut document = content.parse::<toml_edit::DocumentMut>().map_err(|e| CargoError::InvalidToml(e.to_string()))?;
+
+    // Collected while `table` borrows `document`, applied once that borrow ends.
+    let mut patches: Vec<(String, PathBuf)> = Vec::new();
+
+    for section in ["dependencies", "build-dependencies"] {
+        let Some(table) = document.get_mut(section).and_then(|item| item.as_table_like_mut()) else { continue };
+
+        for dep in &package.dependencies {
+            let Some(dep_package) = workspace.resolve_dependency(dep) else { continue };
+            if let Some(item) = table.get_mut(&dep.name) {
+                *item = toml_edit::value(dep_package.version.clone());
+            }
+
+            if run.iter().any(|p| p.id() == dep_package.id()) {
+                patches.push((dep_package.name.clone(), dep_package.path.clone()));
+            }
+        }
+    }
+
+    for (name, path) in &patches {
+        add_patch_override(&mut document, name, path);
+    }
+
+    fs::write(manifest_path, document.to_string())?;
+    Ok(())
+}
+
+/// Adds a `[patch.crates-io]` entry pointing `name` back at its real on-disk
+/// `path`, so the scratch project resolves a not-yet-published sibling
+/// locally instead of asking the real registry for a version that doesn't
+/// exist there yet.
+fn add_patch_override(document: &mut toml_edit::DocumentMut, name: &str, path: &Path) {
+    let mut inline = toml_edit::InlineTable::default();
+    inline.insert("path", path.to_string_lossy().into_owned().into());
+    document["patch"]["crates-io"][name] = toml_edit::Item::Value(toml_edit::Value::InlineTable(inline));
+}
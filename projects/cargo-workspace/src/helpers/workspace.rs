@@ -1,21 +1,100 @@
 use crate::errors::{CargoError, Result};
+use semver::{Version, VersionReq};
 use serde::{Deserialize, Serialize};
 use std::{
     collections::HashMap,
+    fmt::{Display, Formatter},
     fs,
     path::{Path, PathBuf},
 };
 use walkdir::WalkDir;
 use glob::glob;
 
+/// Uniquely identifies a package within a workspace by name *and* version, so
+/// that two crates sharing a name (different major versions pulled in
+/// transitively) don't collide in the dependency graph.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+pub struct PackageId {
+    pub name: String,
+    pub version: String,
+}
+
+impl Display for PackageId {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}@{}", self.name, self.version)
+    }
+}
+
+/// A single dependency edge, carrying the version requirement so it can be
+/// resolved against the specific workspace member it points at, the same way
+/// cargo itself resolves path dependencies.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Dependency {
+    pub name: String,
+    pub version_req: VersionReq,
+    /// Whether the manifest declared an explicit `version`, as opposed to a
+    /// bare `path = "..."` dependency that falls back to matching any version.
+    /// A workspace dependency published without an explicit version breaks,
+    /// since `path` is stripped from the uploaded manifest.
+    pub has_explicit_version: bool,
+}
+
+/// A package's maturity, following the [willbe](https://github.com/Wandalen/wTools)
+/// convention of declaring it under `[package.metadata] stability = "..."`.
+/// Ordered so that `stability >= min_stability` is a meaningful threshold
+/// check: a deprecated package is less stable than an experimental one.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize, clap::ValueEnum)]
+#[serde(rename_all = "lowercase")]
+pub enum Stability {
+    Deprecated,
+    Experimental,
+    Stable,
+}
+
+impl Default for Stability {
+    fn default() -> Self {
+        Stability::Experimental
+    }
+}
+
+impl Display for Stability {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Stability::Deprecated => write!(f, "deprecated"),
+            Stability::Experimental => write!(f, "experimental"),
+            Stability::Stable => write!(f, "stable"),
+        }
+    }
+}
+
 /// Represents a Cargo package
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CargoPackage {
     pub name: String,
     pub version: String,
     pub path: PathBuf,
-    pub dependencies: Vec<String>,
+    pub dependencies: Vec<Dependency>,
     pub publish: bool,
+    /// Whether `version` is declared as `version.workspace = true` rather than
+    /// a literal string, i.e. whether bumping it means rewriting
+    /// `[workspace.package] version` instead of this package's own manifest.
+    pub inherits_workspace_version: bool,
+    /// Declared maturity, from `[package.metadata] stability`. Defaults to
+    /// [Stability::Experimental] when absent.
+    pub stability: Stability,
+    /// `[package] description`, required by crates.io.
+    pub description: Option<String>,
+    /// `[package] license`, one of the two ways crates.io accepts licensing info.
+    pub license: Option<String>,
+    /// `[package] license-file`, the other way crates.io accepts licensing info.
+    pub license_file: Option<String>,
+}
+
+impl CargoPackage {
+    /// This package's identity within the workspace.
+    pub fn id(&self) -> PackageId {
+        PackageId { name: self.name.clone(), version: self.version.clone() }
+    }
 }
 
 /// Represents a Cargo workspace
@@ -23,7 +102,61 @@ pub struct CargoPackage {
 pub struct CargoWorkspace {
     pub root: PathBuf,
     pub members: Vec<PathBuf>,
-    pub packages: HashMap<String, CargoPackage>,
+    pub packages: HashMap<PackageId, CargoPackage>,
+}
+
+impl CargoWorkspace {
+    /// Resolves an intra-workspace dependency the way cargo resolves a path
+    /// dependency: by name, picking the workspace member whose version
+    /// satisfies the requirement.
+    pub fn resolve_dependency(&self, dependency: &Dependency) -> Option<&CargoPackage> {
+        self.packages.values().find(|package| {
+            package.name == dependency.name
+                && Version::parse(&package.version).map(|v| dependency.version_req.matches(&v)).unwrap_or(false)
+        })
+    }
+}
+
+/// Parses a dependency's version requirement string, falling back to
+/// matching any version when it isn't valid semver (e.g. a git rev).
+fn parse_version_req(req: &str) -> VersionReq {
+    VersionReq::parse(req).unwrap_or(VersionReq::STAR)
+}
+
+/// Resolves a `[package]` string field that may be declared as a literal or
+/// as `<field>.workspace = true`, the same inheritance mechanism `version`
+/// handles explicitly in [parse_cargo_toml_with_workspace]. Returns `None`
+/// when the field is absent, when it is a table other than
+/// `{ workspace = true }`, or when it inherits from a `[workspace.package]`
+/// that is missing or does not declare the field as a string.
+fn resolve_inheritable_string(package: &toml::Value, field: &str, workspace_package: Option<&toml::Value>) -> Option<String> {
+    let value = package.get(field)?;
+
+    if let Some(s) = value.as_str() {
+        return Some(s.to_string());
+    }
+
+    let table = value.as_table()?;
+    if !table.get("workspace").and_then(|v| v.as_bool()).unwrap_or(false) {
+        return None;
+    }
+
+    workspace_package.and_then(|ws_pkg| ws_pkg.get(field)).and_then(|v| v.as_str()).map(|s| s.to_string())
+}
+
+/// Parses `[package.metadata] stability`, defaulting to [Stability::Experimental].
+fn parse_stability(package: &toml::Value) -> Stability {
+    package
+        .get("metadata")
+        .and_then(|metadata| metadata.get("stability"))
+        .and_then(|value| value.as_str())
+        .and_then(|value| match value.to_ascii_lowercase().as_str() {
+            "stable" => Some(Stability::Stable),
+            "experimental" => Some(Stability::Experimental),
+            "deprecated" => Some(Stability::Deprecated),
+            _ => None,
+        })
+        .unwrap_or_default()
 }
 
 /// Finds the workspace root by searching for Cargo.toml
@@ -70,12 +203,14 @@ pub fn parse_cargo_toml_with_workspace(path: &Path, workspace_package: Option<&t
         .to_string();
 
     // Handle workspace inheritance for version
+    let mut inherits_workspace_version = false;
     let version = if let Some(version_value) = package.get("version") {
         if let Some(version_str) = version_value.as_str() {
             version_str.to_string()
         } else if let Some(version_table) = version_value.as_table() {
             // Check for workspace inheritance
             if version_table.get("workspace").and_then(|v| v.as_bool()).unwrap_or(false) {
+                inherits_workspace_version = true;
                 // Try to get version from workspace package
                 if let Some(ws_pkg) = workspace_package {
                     ws_pkg.get("version")
@@ -96,6 +231,10 @@ pub fn parse_cargo_toml_with_workspace(path: &Path, workspace_package: Option<&t
     };
 
     let publish = package.get("publish").and_then(|v| v.as_bool()).unwrap_or(true);
+    let stability = parse_stability(package);
+    let description = resolve_inheritable_string(package, "description", workspace_package);
+    let license = resolve_inheritable_string(package, "license", workspace_package);
+    let license_file = resolve_inheritable_string(package, "license-file", workspace_package);
 
     let mut dependencies = Vec::new();
 
@@ -108,23 +247,93 @@ pub fn parse_cargo_toml_with_workspace(path: &Path, workspace_package: Option<&t
                     if dep_name == &name {
                         continue;
                     }
-                    
-                    // Handle workspace dependencies that have workspace = true
-                    if let Some(dep_obj) = dep_value.as_table() {
-                        if dep_obj.get("workspace").and_then(|v| v.as_bool()).unwrap_or(false) {
-                            // This is a workspace dependency, add it to dependencies
-                            dependencies.push(dep_name.clone());
-                            continue;
-                        }
-                    }
-                    // Add regular dependencies
-                    dependencies.push(dep_name.clone());
+
+                    let (version_req, has_explicit_version) = match dep_value {
+                        // `dep = "1.2"` or equivalent
+                        toml::Value::String(req) => (parse_version_req(req), true),
+                        // `dep = { version = "1.2", workspace = true, ... }`
+                        toml::Value::Table(dep_obj) => match dep_obj.get("version").and_then(|v| v.as_str()) {
+                            Some(req) => (parse_version_req(req), true),
+                            // No explicit requirement (e.g. a bare `path = "..."` dep):
+                            // match any version of the workspace member.
+                            None => (VersionReq::STAR, false),
+                        },
+                        _ => (VersionReq::STAR, false),
+                    };
+
+                    dependencies.push(Dependency { name: dep_name.clone(), version_req, has_explicit_version });
                 }
             }
         }
     }
 
-    Ok(CargoPackage { name, version, path: path.parent().unwrap_or(path).to_path_buf(), dependencies, publish })
+    Ok(CargoPackage {
+        name,
+        version,
+        path: path.parent().unwrap_or(path).to_path_buf(),
+        dependencies,
+        publish,
+        inherits_workspace_version,
+        stability,
+        description,
+        license,
+        license_file,
+    })
+}
+
+/// Rewrites a manifest's `version` field in place using `toml_edit`, so that
+/// comments and formatting elsewhere in the file survive the edit.
+///
+/// When `inherits_workspace_version` is set, `manifest_path` must be the
+/// workspace root's `Cargo.toml` and `[workspace.package] version` is updated
+/// instead of `[package] version`.
+pub fn write_package_version(manifest_path: &Path, new_version: &str, inherits_workspace_version: bool) -> Result<()> {
+    let content = fs::read_to_string(manifest_path)?;
+    let mut document = content.parse::<toml_edit::DocumentMut>().map_err(|e| CargoError::InvalidToml(e.to_string()))?;
+
+    let table = if inherits_workspace_version {
+        document
+            .get_mut("workspace")
+            .and_then(|w| w.get_mut("package"))
+            .ok_or_else(|| CargoError::InvalidToml("Missing [workspace.package] section".to_string()))?
+    } else {
+        document.get_mut("package").ok_or_else(|| CargoError::InvalidToml("Missing [package] section".to_string()))?
+    };
+
+    table["version"] = toml_edit::value(new_version);
+    fs::write(manifest_path, document.to_string())?;
+    Ok(())
+}
+
+/// Rewrites a single dependency's version requirement in place within
+/// `manifest_path`'s `[dependencies]`/`[build-dependencies]` tables, preserving
+/// formatting via `toml_edit`. Used to propagate a workspace dependency's
+/// version bump into its dependents, the way `cargo` does not do for you.
+pub fn write_dependency_requirement(manifest_path: &Path, dep_name: &str, new_version: &str) -> Result<()> {
+    let content = fs::read_to_string(manifest_path)?;
+    let mut document = content.parse::<toml_edit::DocumentMut>().map_err(|e| CargoError::InvalidToml(e.to_string()))?;
+
+    let mut updated = false;
+    for section in ["dependencies", "build-dependencies"] {
+        let Some(table) = document.get_mut(section).and_then(|item| item.as_table_mut()) else { continue };
+        let Some(item) = table.get_mut(dep_name) else { continue };
+
+        match item {
+            toml_edit::Item::Value(toml_edit::Value::InlineTable(inline)) => {
+                inline.insert("version", new_version.into());
+            }
+            toml_edit::Item::Table(dep_table) => {
+                dep_table["version"] = toml_edit::value(new_version);
+            }
+            _ => *item = toml_edit::value(new_version),
+        }
+        updated = true;
+    }
+
+    if updated {
+        fs::write(manifest_path, document.to_string())?;
+    }
+    Ok(())
 }
 
 /// Expands a glob pattern to matching paths
@@ -182,7 +391,7 @@ pub fn discover_workspace_packages(workspace_root: &Path) -> Result<CargoWorkspa
     // Parse the workspace root package if it exists
     if workspace_cargo_toml.exists() {
         if let Ok(package) = parse_cargo_toml_with_workspace(&workspace_cargo_toml, workspace_package) {
-            packages.insert(package.name.clone(), package);
+            packages.insert(package.id(), package);
         }
     }
 
@@ -190,14 +399,14 @@ pub fn discover_workspace_packages(workspace_root: &Path) -> Result<CargoWorkspa
     for member_pattern in &members {
         // Expand glob patterns
         let expanded_paths = expand_glob_pattern(workspace_root, member_pattern)?;
-        
+
         for member_path in expanded_paths {
             member_paths.push(member_path.clone());
-            
+
             let cargo_toml = member_path.join("Cargo.toml");
             if cargo_toml.exists() {
                 if let Ok(package) = parse_cargo_toml_with_workspace(&cargo_toml, workspace_package) {
-                    packages.insert(package.name.clone(), package);
+                    packages.insert(package.id(), package);
                 }
             }
         }